@@ -3,6 +3,12 @@
 
 use raylib::prelude::*;
 use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
 
 // =============================================================================
 // Constants
@@ -20,6 +26,14 @@ const FROG_START_COL: i32 = GRID_COLS / 2;        // Center
 const INITIAL_LIVES: i32 = 3;
 const GOAL_COUNT: usize = 5;
 
+// Default challenge code used before the player enters their own.
+const DEFAULT_TRACK_CODE: &str = "FROGGER";
+
+// Persistent settings file and the bounds difficulty is validated against.
+const CONFIG_FILE: &str = "frogger.cfg";
+const DIFFICULTY_MIN: f32 = 0.5;
+const DIFFICULTY_MAX: f32 = 2.0;
+
 // Lane configuration: row index -> lane type
 // Row 0: Goal area (lily pads)
 // Rows 1-5: River (logs/turtles)
@@ -196,6 +210,126 @@ struct GoalSlot {
     occupied: bool,
 }
 
+// A single frame of the frog's path during a run. A full run is a trajectory
+// of these, sampled one per frame, used to draw a "ghost" of the player's best
+// line so they can race it.
+#[derive(Clone, Copy)]
+struct GhostMoment {
+    t: f32,              // Seconds since the run started
+    x: f32,              // Frog pixel x (riding offset included)
+    y: f32,              // Frog pixel y
+    riding_offset: f32,  // Kept for completeness / future log-aware rendering
+}
+
+// HUD presentation modes selectable from the options screen.
+#[derive(Clone, Copy, PartialEq)]
+enum HudStyle {
+    Off,
+    Default,
+    Classic, // Top-bar lives/score, arcade style
+}
+
+impl HudStyle {
+    // Cycle through the modes for the options screen.
+    fn next(self) -> Self {
+        match self {
+            HudStyle::Off => HudStyle::Default,
+            HudStyle::Default => HudStyle::Classic,
+            HudStyle::Classic => HudStyle::Off,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            HudStyle::Off => "Off",
+            HudStyle::Default => "Default",
+            HudStyle::Classic => "Classic",
+        }
+    }
+
+    // Parse a persisted value back to a mode, rejecting anything unknown so the
+    // caller can fall back to the default.
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "Off" => Some(HudStyle::Off),
+            "Default" => Some(HudStyle::Default),
+            "Classic" => Some(HudStyle::Classic),
+            _ => None,
+        }
+    }
+}
+
+// Bounded, typed settings persisted between sessions. Out-of-range or missing
+// values fall back to the defaults below when loaded.
+struct Config {
+    hud_style: HudStyle,
+    difficulty: f32,     // Multiplier on base lane speeds
+    ghost_enabled: bool, // Master switch for the ghost overlay
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            hud_style: HudStyle::Default,
+            difficulty: 1.0,
+            ghost_enabled: true,
+        }
+    }
+}
+
+impl Config {
+    // Load from the config file, validating each field and falling back to the
+    // default for any entry that is missing or out of range.
+    fn load() -> Self {
+        let mut config = Config::default();
+        let contents = match fs::read_to_string(CONFIG_FILE) {
+            Ok(c) => c,
+            Err(_) => return config,
+        };
+        for line in contents.lines() {
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap_or("").trim();
+            let value = match parts.next() {
+                Some(v) => v.trim(),
+                None => continue,
+            };
+            match key {
+                "hud_style" => {
+                    if let Some(style) = HudStyle::from_str(value) {
+                        config.hud_style = style;
+                    }
+                }
+                "difficulty" => {
+                    if let Ok(d) = value.parse::<f32>() {
+                        if (DIFFICULTY_MIN..=DIFFICULTY_MAX).contains(&d) {
+                            config.difficulty = d;
+                        }
+                    }
+                }
+                "ghost_enabled" => {
+                    if let Ok(b) = value.parse::<bool>() {
+                        config.ghost_enabled = b;
+                    }
+                }
+                _ => {}
+            }
+        }
+        config
+    }
+
+    fn save(&self) {
+        let out = format!(
+            "hud_style={}\ndifficulty={}\nghost_enabled={}\n",
+            self.hud_style.label(),
+            self.difficulty,
+            self.ghost_enabled,
+        );
+        if let Ok(mut file) = fs::File::create(CONFIG_FILE) {
+            let _ = file.write_all(out.as_bytes());
+        }
+    }
+}
+
 struct GameState {
     frog: Frog,
     cars: Vec<MovingObject>,
@@ -205,10 +339,36 @@ struct GameState {
     score: i32,
     game_over: bool,
     won: bool,
+    run_time: f32,                    // Elapsed time of the current run
+    ghost_current: Vec<GhostMoment>,  // Trajectory being recorded this run
+    ghost_best: Option<Vec<GhostMoment>>, // Fastest trajectory seen so far
+    rng: StdRng,                      // Seeded generator for repeatable layouts
+    seed: u64,                        // Active 64-bit seed (from code + try)
+    track_code: String,               // Human-enterable challenge code
+    race_try: u32,                    // Attempt counter mixed into the seed
+    race_started: bool,               // Clock runs once the first move is made
+    race_start: f64,                  // Wall-clock time the race began
+    race_finish: Option<f64>,         // Wall-clock time all goals filled
+    best_times: HashMap<u64, f64>,    // Fastest finish per seed
+    last_delta: Option<f32>,          // Delta to previous best on the last win
+    config: Config,                   // Persistent, validated settings
+}
+
+// Fold a challenge code and attempt counter into a 64-bit seed with a simple
+// rolling hash. Two players on the same code and try face an identical board;
+// bumping `race_try` reshuffles the layout for a fresh attempt on the same code.
+fn derive_seed(track_code: &str, race_try: u32) -> u64 {
+    let mut seed: u64 = 0;
+    for byte in track_code.bytes() {
+        seed = seed.wrapping_mul(713).wrapping_add(byte as u64);
+    }
+    seed.wrapping_mul(713).wrapping_add(race_try as u64)
 }
 
 impl GameState {
     fn new() -> Self {
+        let track_code = DEFAULT_TRACK_CODE.to_string();
+        let seed = derive_seed(&track_code, 0);
         let mut state = Self {
             frog: Frog::new(),
             cars: Vec::new(),
@@ -218,12 +378,34 @@ impl GameState {
             score: 0,
             game_over: false,
             won: false,
+            run_time: 0.0,
+            ghost_current: Vec::new(),
+            ghost_best: load_ghost(seed),
+            rng: StdRng::seed_from_u64(seed),
+            seed,
+            track_code,
+            race_try: 0,
+            race_started: false,
+            race_start: 0.0,
+            race_finish: None,
+            best_times: load_best_times(),
+            last_delta: None,
+            config: Config::load(),
         };
         state.init_lanes();
         state.init_goals();
         state
     }
 
+    // Recompute the seed from the current code and try, reseed the generator,
+    // and reload the ghost recorded for that seed. Call before regenerating the
+    // board so layout, jitter, and speeds are reproducible.
+    fn reseed(&mut self) {
+        self.seed = derive_seed(&self.track_code, self.race_try);
+        self.rng = StdRng::seed_from_u64(self.seed);
+        self.ghost_best = load_ghost(self.seed);
+    }
+
     fn init_goals(&mut self) {
         // Create 5 goal slots evenly spaced at the top
         self.goals.clear();
@@ -237,7 +419,7 @@ impl GameState {
     }
 
     fn init_lanes(&mut self) {
-        let mut rng = rand::thread_rng();
+        let difficulty = self.config.difficulty;
 
         self.cars.clear();
         self.logs.clear();
@@ -254,11 +436,11 @@ impl GameState {
 
         for (row, width, speed, dir, color) in car_configs {
             // Add 2-3 vehicles per lane
-            let count = rng.gen_range(2..=3);
+            let count = self.rng.gen_range(2..=3);
             let spacing = SCREEN_WIDTH as f32 / count as f32;
             for i in 0..count {
-                let x = i as f32 * spacing + rng.gen_range(-20.0..20.0);
-                self.cars.push(MovingObject::new(x, row, width, speed, dir, color));
+                let x = i as f32 * spacing + self.rng.gen_range(-20.0..20.0);
+                self.cars.push(MovingObject::new(x, row, width, speed * difficulty, dir, color));
             }
         }
 
@@ -272,11 +454,11 @@ impl GameState {
         ];
 
         for (row, width, speed, dir) in log_configs {
-            let count = rng.gen_range(2..=3);
+            let count = self.rng.gen_range(2..=3);
             let spacing = SCREEN_WIDTH as f32 / count as f32;
             for i in 0..count {
-                let x = i as f32 * spacing + rng.gen_range(-30.0..30.0);
-                self.logs.push(MovingObject::new(x, row, width, speed, dir, Color::SADDLEBROWN));
+                let x = i as f32 * spacing + self.rng.gen_range(-30.0..30.0);
+                self.logs.push(MovingObject::new(x, row, width, speed * difficulty, dir, Color::SADDLEBROWN));
             }
         }
     }
@@ -287,11 +469,27 @@ impl GameState {
         self.score = 0;
         self.game_over = false;
         self.won = false;
+        self.run_time = 0.0;
+        self.ghost_current.clear();
+        self.race_started = false;
+        self.race_start = 0.0;
+        self.race_finish = None;
+        self.last_delta = None;
+        self.reseed();
         self.init_lanes();
         self.init_goals();
     }
 
+    // Start the time-attack clock on the player's first move.
+    fn start_race(&mut self, now: f64) {
+        if !self.race_started {
+            self.race_started = true;
+            self.race_start = now;
+        }
+    }
+
     fn kill_frog(&mut self) {
+        self.finalize_ghost(false);
         self.lives -= 1;
         if self.lives <= 0 {
             self.game_over = true;
@@ -300,7 +498,26 @@ impl GameState {
         }
     }
 
-    fn check_goal_reached(&mut self) {
+    // End the current run and clear its recording. Only a run that completed all
+    // five goals is a candidate for the best ghost — a death has a short
+    // `run_time` but is not a line worth racing, so `completed` gates the
+    // keep/compare entirely.
+    fn finalize_ghost(&mut self, completed: bool) {
+        if completed && !self.ghost_current.is_empty() {
+            let beats_best = match &self.ghost_best {
+                Some(best) => self.run_time < best.last().map_or(f32::MAX, |m| m.t),
+                None => true,
+            };
+            if beats_best {
+                save_ghost(self.seed, &self.ghost_current);
+                self.ghost_best = Some(std::mem::take(&mut self.ghost_current));
+            }
+        }
+        self.ghost_current.clear();
+        self.run_time = 0.0;
+    }
+
+    fn check_goal_reached(&mut self, now: f64) {
         if self.frog.y == 0 {
             let frog_center = self.frog.get_pixel_x() + (CELL_SIZE / 2) as f32;
 
@@ -316,6 +533,8 @@ impl GameState {
 
                         // Check win condition
                         if self.goals.iter().all(|g| g.occupied) {
+                            self.finalize_ghost(true);
+                            self.finish_race(now);
                             self.won = true;
                             self.game_over = true;
                         }
@@ -329,11 +548,46 @@ impl GameState {
         }
     }
 
-    fn update(&mut self, dt: f32) {
+    // Stop the clock and record the finish, saving it as the new best for this
+    // seed if it beats the stored time and remembering the delta to show it.
+    fn finish_race(&mut self, now: f64) {
+        if self.race_finish.is_some() {
+            return;
+        }
+        self.race_finish = Some(now);
+        let elapsed = now - self.race_start;
+        let previous = self.best_times.get(&self.seed).copied();
+        self.last_delta = previous.map(|p| (elapsed - p) as f32);
+        if previous.map_or(true, |p| elapsed < p) {
+            self.best_times.insert(self.seed, elapsed);
+            save_best_times(&self.best_times);
+        }
+    }
+
+    // Seconds elapsed in the current race, frozen at the finish time.
+    fn race_elapsed(&self, now: f64) -> f32 {
+        if !self.race_started {
+            return 0.0;
+        }
+        let end = self.race_finish.unwrap_or(now);
+        (end - self.race_start) as f32
+    }
+
+    fn update(&mut self, dt: f32, now: f64) {
         if self.game_over {
             return;
         }
 
+        // Record this frame into the ghost trajectory before anything can end
+        // the run, so the final sample reflects the position that won or died.
+        self.run_time += dt;
+        self.ghost_current.push(GhostMoment {
+            t: self.run_time,
+            x: self.frog.get_pixel_x(),
+            y: self.frog.get_pixel_y(),
+            riding_offset: self.frog.riding_offset,
+        });
+
         // Update moving objects
         for car in &mut self.cars {
             car.update(dt);
@@ -393,7 +647,7 @@ impl GameState {
         }
 
         // Check if reached goal row
-        self.check_goal_reached();
+        self.check_goal_reached(now);
     }
 }
 
@@ -408,6 +662,225 @@ fn check_collision_recs(r1: Rectangle, r2: Rectangle) -> bool {
         && r1.y + r1.height > r2.y
 }
 
+// =============================================================================
+// Gamepad input
+// =============================================================================
+
+// Analog stick thresholds: a move fires once the axis passes THRESHOLD, and the
+// stick must fall back inside NEUTRAL before another move can fire. This
+// edge-triggers the continuous axis into one discrete cell step per flick.
+const AXIS_THRESHOLD: f32 = 0.5;
+const AXIS_NEUTRAL: f32 = 0.25;
+
+// Which controller buttons and axes drive each action. Controllers disagree on
+// axis and button numbering, so the table is overridable; `standard()` is the
+// common SDL-style layout (D-pad + left stick, bottom face button to confirm).
+struct InputBindings {
+    up: GamepadButton,
+    down: GamepadButton,
+    left: GamepadButton,
+    right: GamepadButton,
+    confirm: GamepadButton,
+    axis_x: GamepadAxis,
+    axis_y: GamepadAxis,
+}
+
+impl InputBindings {
+    fn standard() -> Self {
+        Self {
+            up: GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_UP,
+            down: GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_DOWN,
+            left: GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_LEFT,
+            right: GamepadButton::GAMEPAD_BUTTON_LEFT_FACE_RIGHT,
+            confirm: GamepadButton::GAMEPAD_BUTTON_RIGHT_FACE_DOWN,
+            axis_x: GamepadAxis::GAMEPAD_AXIS_LEFT_X,
+            axis_y: GamepadAxis::GAMEPAD_AXIS_LEFT_Y,
+        }
+    }
+}
+
+// The discrete actions a controller poll can yield in a single frame.
+#[derive(Default)]
+struct PadEvents {
+    up: bool,
+    down: bool,
+    left: bool,
+    right: bool,
+    confirm: bool,
+}
+
+// A gamepad reader that turns D-pad presses and analog stick flicks into the
+// same discrete moves the keyboard produces, keeping per-axis neutral state so
+// a held stick does not spam repeats.
+struct Gamepad {
+    bindings: InputBindings,
+    gamepad: i32,
+    x_neutral: bool,
+    y_neutral: bool,
+}
+
+impl Gamepad {
+    fn new() -> Self {
+        Self {
+            bindings: InputBindings::standard(),
+            gamepad: 0,
+            x_neutral: true,
+            y_neutral: true,
+        }
+    }
+
+    fn poll(&mut self, rl: &RaylibHandle) -> PadEvents {
+        let mut ev = PadEvents::default();
+        if !rl.is_gamepad_available(self.gamepad) {
+            // Treat a disconnected pad as neutral so a reconnect starts clean.
+            self.x_neutral = true;
+            self.y_neutral = true;
+            return ev;
+        }
+
+        let b = &self.bindings;
+        ev.up = rl.is_gamepad_button_pressed(self.gamepad, b.up);
+        ev.down = rl.is_gamepad_button_pressed(self.gamepad, b.down);
+        ev.left = rl.is_gamepad_button_pressed(self.gamepad, b.left);
+        ev.right = rl.is_gamepad_button_pressed(self.gamepad, b.right);
+        ev.confirm = rl.is_gamepad_button_pressed(self.gamepad, b.confirm);
+
+        // Vertical stick: up is negative in raylib's convention.
+        let y = rl.get_gamepad_axis_movement(self.gamepad, b.axis_y);
+        if self.y_neutral {
+            if y <= -AXIS_THRESHOLD {
+                ev.up = true;
+                self.y_neutral = false;
+            } else if y >= AXIS_THRESHOLD {
+                ev.down = true;
+                self.y_neutral = false;
+            }
+        } else if y.abs() < AXIS_NEUTRAL {
+            self.y_neutral = true;
+        }
+
+        let x = rl.get_gamepad_axis_movement(self.gamepad, b.axis_x);
+        if self.x_neutral {
+            if x <= -AXIS_THRESHOLD {
+                ev.left = true;
+                self.x_neutral = false;
+            } else if x >= AXIS_THRESHOLD {
+                ev.right = true;
+                self.x_neutral = false;
+            }
+        } else if x.abs() < AXIS_NEUTRAL {
+            self.x_neutral = true;
+        }
+
+        ev
+    }
+}
+
+// =============================================================================
+// Ghost persistence
+// =============================================================================
+
+// Ghosts are stored per seed so each challenge code keeps its own best line.
+fn ghost_file(seed: u64) -> String {
+    format!("ghost_{:016x}.dat", seed)
+}
+
+// The ghost file is a tiny line-oriented text format: one moment per line,
+// "t x y riding_offset". Plain text keeps it dependency-free and easy to
+// eyeball, which suits a file this small.
+fn save_ghost(seed: u64, ghost: &[GhostMoment]) {
+    let mut out = String::new();
+    for m in ghost {
+        out.push_str(&format!("{} {} {} {}\n", m.t, m.x, m.y, m.riding_offset));
+    }
+    if let Ok(mut file) = fs::File::create(ghost_file(seed)) {
+        let _ = file.write_all(out.as_bytes());
+    }
+}
+
+fn load_ghost(seed: u64) -> Option<Vec<GhostMoment>> {
+    let contents = fs::read_to_string(ghost_file(seed)).ok()?;
+    let mut ghost = Vec::new();
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        let t = parts.next()?.parse().ok()?;
+        let x = parts.next()?.parse().ok()?;
+        let y = parts.next()?.parse().ok()?;
+        let riding_offset = parts.next()?.parse().ok()?;
+        ghost.push(GhostMoment { t, x, y, riding_offset });
+    }
+    if ghost.is_empty() {
+        None
+    } else {
+        Some(ghost)
+    }
+}
+
+// Best finish times live in a single file, one "seed time" line per seed, so
+// per-seed bests and win deltas survive a relaunch like the ghosts do.
+const BEST_TIMES_FILE: &str = "best_times.dat";
+
+fn save_best_times(best_times: &HashMap<u64, f64>) {
+    let mut out = String::new();
+    for (seed, time) in best_times {
+        out.push_str(&format!("{} {}\n", seed, time));
+    }
+    if let Ok(mut file) = fs::File::create(BEST_TIMES_FILE) {
+        let _ = file.write_all(out.as_bytes());
+    }
+}
+
+fn load_best_times() -> HashMap<u64, f64> {
+    let mut best_times = HashMap::new();
+    let contents = match fs::read_to_string(BEST_TIMES_FILE) {
+        Ok(c) => c,
+        Err(_) => return best_times,
+    };
+    for line in contents.lines() {
+        let mut parts = line.split_whitespace();
+        if let (Some(seed), Some(time)) = (parts.next(), parts.next()) {
+            if let (Ok(seed), Ok(time)) = (seed.parse(), time.parse()) {
+                best_times.insert(seed, time);
+            }
+        }
+    }
+    best_times
+}
+
+// Interpolate the ghost's pixel position for the given elapsed time, linearly
+// lerping between the two bracketing moments. Past the end of the trajectory
+// the position clamps to the final sample.
+fn sample_ghost(ghost: &[GhostMoment], t: f32) -> (f32, f32) {
+    let first = ghost[0];
+    if t <= first.t {
+        return (first.x, first.y);
+    }
+    for pair in ghost.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if t < b.t {
+            let span = b.t - a.t;
+            let frac = if span > 0.0 { (t - a.t) / span } else { 0.0 };
+            return (a.x + (b.x - a.x) * frac, a.y + (b.y - a.y) * frac);
+        }
+    }
+    let last = ghost[ghost.len() - 1];
+    (last.x, last.y)
+}
+
+// Format a race time as an `M:SS.cc` string with seconds and centiseconds
+// zero-padded, so times under ten minutes line up on screen. Minutes are not
+// padded: from ten minutes on the string is one character wider. A negative
+// value keeps its leading `-`, which lets the same formatter render deltas to a
+// previous best.
+fn format_race_time(secs: f32) -> String {
+    let sign = if secs < 0.0 { "-" } else { "" };
+    let total_cs = (secs.abs() * 100.0).round() as i64;
+    let minutes = total_cs / 6000;
+    let seconds = (total_cs / 100) % 60;
+    let centis = total_cs % 100;
+    format!("{}{}:{:02}.{:02}", sign, minutes, seconds, centis)
+}
+
 // =============================================================================
 // Rendering
 // =============================================================================
@@ -571,19 +1044,138 @@ fn draw_frog(d: &mut RaylibDrawHandle, frog: &Frog) {
     d.draw_rectangle(x + CELL_SIZE - 8, y + CELL_SIZE - 8, 6, 6, Color::DARKGREEN);
 }
 
-fn draw_hud(d: &mut RaylibDrawHandle, lives: i32, score: i32) {
-    // Lives
-    d.draw_text("Lives:", 10, SCREEN_HEIGHT - 28, 20, Color::WHITE);
-    for i in 0..lives {
-        d.draw_circle(80 + i * 25, SCREEN_HEIGHT - 18, 8.0, Color::GREEN);
+// Draw a translucent green frog at the ghost's interpolated position for the
+// current run time. Mirrors draw_frog's body but at reduced alpha so the
+// player can see their best line race alongside them.
+fn draw_ghost(d: &mut RaylibDrawHandle, ghost: &[GhostMoment], run_time: f32) {
+    if ghost.is_empty() {
+        return;
     }
+    let (px, py) = sample_ghost(ghost, run_time);
+    let center_x = px as i32 + CELL_SIZE / 2;
+    let center_y = py as i32 + CELL_SIZE / 2;
+    d.draw_circle(
+        center_x,
+        center_y,
+        (CELL_SIZE / 2 - 4) as f32,
+        Color::new(0, 255, 0, 110),
+    );
+}
 
-    // Score
-    let score_text = format!("Score: {}", score);
-    d.draw_text(&score_text, SCREEN_WIDTH - 150, SCREEN_HEIGHT - 28, 20, Color::WHITE);
+fn draw_hud(
+    d: &mut RaylibDrawHandle,
+    style: HudStyle,
+    lives: i32,
+    score: i32,
+    track_code: &str,
+    race_time: f32,
+) {
+    match style {
+        HudStyle::Off => {}
+        HudStyle::Default => {
+            // Lives
+            d.draw_text("Lives:", 10, SCREEN_HEIGHT - 28, 20, Color::WHITE);
+            for i in 0..lives {
+                d.draw_circle(80 + i * 25, SCREEN_HEIGHT - 18, 8.0, Color::GREEN);
+            }
+
+            // Live race clock
+            let clock = format_race_time(race_time);
+            d.draw_text(&clock, SCREEN_WIDTH / 2 - 30, 6, 24, Color::WHITE);
+
+            // Active challenge code
+            let code_text = format!("Code: {}", track_code);
+            d.draw_text(&code_text, SCREEN_WIDTH / 2 - 60, SCREEN_HEIGHT - 28, 20, Color::WHITE);
+
+            // Score
+            let score_text = format!("Score: {}", score);
+            d.draw_text(&score_text, SCREEN_WIDTH - 150, SCREEN_HEIGHT - 28, 20, Color::WHITE);
+        }
+        HudStyle::Classic => {
+            // Retro top bar: lives and score on one line across the top.
+            let lives_text = format!("LIVES {}", lives);
+            d.draw_text(&lives_text, 10, 6, 20, Color::WHITE);
+
+            let clock = format_race_time(race_time);
+            d.draw_text(&clock, SCREEN_WIDTH / 2 - 30, 6, 20, Color::WHITE);
+
+            let score_text = format!("SCORE {}", score);
+            d.draw_text(&score_text, SCREEN_WIDTH - 150, 6, 20, Color::WHITE);
+        }
+    }
+}
+
+// Draw the objective feedback the base HUD lacks: overall completion as a
+// percentage, plus a pulsing marker over the nearest empty lily pad with the
+// frog's remaining row-distance to the goal row.
+fn draw_progress(d: &mut RaylibDrawHandle, style: HudStyle, goals: &[GoalSlot], frog: &Frog, time: f32) {
+    // Honour an explicitly disabled HUD: no overlay when the player wants none.
+    if style == HudStyle::Off {
+        return;
+    }
+
+    let occupied = goals.iter().filter(|g| g.occupied).count();
+    let percent = occupied * 100 / GOAL_COUNT;
+    let text = format!("{}%", percent);
+    // Sit just below the top bar so it never overlaps the Classic HUD line.
+    d.draw_text(&text, 10, CELL_SIZE + 4, 24, Color::WHITE);
+
+    // Nearest unoccupied pad, measured by horizontal distance to the frog.
+    let frog_center = frog.get_pixel_x() + (CELL_SIZE / 2) as f32;
+    let nearest = goals
+        .iter()
+        .filter(|g| !g.occupied)
+        .min_by(|a, b| {
+            let da = ((a.x * CELL_SIZE + CELL_SIZE / 2) as f32 - frog_center).abs();
+            let db = ((b.x * CELL_SIZE + CELL_SIZE / 2) as f32 - frog_center).abs();
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+    if let Some(goal) = nearest {
+        // Pulse the alpha with a sine of the elapsed time.
+        let pulse = ((time * 4.0).sin() * 0.5 + 0.5) * 255.0;
+        let color = Color::new(255, 255, 0, pulse as u8);
+        let cx = goal.x * CELL_SIZE + CELL_SIZE / 2;
+        let cy = CELL_SIZE / 2;
+        let r = (CELL_SIZE / 3) as i32;
+        d.draw_line(cx - r, cy - r, cx + r, cy + r, color);
+        d.draw_line(cx - r, cy + r, cx + r, cy - r, color);
+
+        // Remaining rows from the frog up to the goal row (row 0).
+        let rows_left = frog.y;
+        let dist_text = format!("{}", rows_left);
+        d.draw_text(&dist_text, cx + r + 4, cy - 8, 16, color);
+    }
 }
 
-fn draw_game_over(d: &mut RaylibDrawHandle, won: bool) {
+// Options screen reachable from the game-over menu. Keys mutate the live config;
+// changes are saved when the screen closes.
+fn draw_options(d: &mut RaylibDrawHandle, config: &Config) {
+    let overlay = Color::new(0, 0, 0, 200);
+    d.draw_rectangle(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT, overlay);
+
+    let title = "OPTIONS";
+    let tw = measure_text(title, 36);
+    d.draw_text(title, SCREEN_WIDTH / 2 - tw / 2, 60, 36, Color::GOLD);
+
+    let lines = [
+        format!("H     HUD style:  {}", config.hud_style.label()),
+        format!("-/+   Difficulty: {:.2}", config.difficulty),
+        format!("G     Ghost:      {}", if config.ghost_enabled { "On" } else { "Off" }),
+        "ENTER Back".to_string(),
+    ];
+    for (i, line) in lines.iter().enumerate() {
+        d.draw_text(line, SCREEN_WIDTH / 2 - 140, 140 + i as i32 * 34, 22, Color::WHITE);
+    }
+}
+
+fn draw_game_over(
+    d: &mut RaylibDrawHandle,
+    won: bool,
+    track_code: &str,
+    race_time: f32,
+    last_delta: Option<f32>,
+) {
     let overlay = Color::new(0, 0, 0, 180);
     d.draw_rectangle(0, 0, SCREEN_WIDTH, SCREEN_HEIGHT, overlay);
 
@@ -592,17 +1184,42 @@ fn draw_game_over(d: &mut RaylibDrawHandle, won: bool) {
     d.draw_text(
         message,
         SCREEN_WIDTH / 2 - text_width / 2,
-        SCREEN_HEIGHT / 2 - 40,
+        SCREEN_HEIGHT / 2 - 70,
         40,
         if won { Color::GOLD } else { Color::RED },
     );
 
-    let restart = "Press SPACE to restart";
+    // Final time and, when a previous best existed, the delta against it.
+    if won {
+        let time_line = format!("Time: {}", format_race_time(race_time));
+        let tw = measure_text(&time_line, 24);
+        d.draw_text(&time_line, SCREEN_WIDTH / 2 - tw / 2, SCREEN_HEIGHT / 2 - 38, 24, Color::WHITE);
+
+        if let Some(delta) = last_delta {
+            let delta_line = format!("Best {}", format_race_time(delta));
+            let dw = measure_text(&delta_line, 20);
+            let color = if delta <= 0.0 { Color::LIME } else { Color::ORANGE };
+            d.draw_text(&delta_line, SCREEN_WIDTH / 2 - dw / 2, SCREEN_HEIGHT / 2 - 60, 20, color);
+        }
+    }
+
+    // Challenge-code entry field: type to edit, BACKSPACE to delete.
+    let code_line = format!("Code: {}_", track_code);
+    let code_width = measure_text(&code_line, 24);
+    d.draw_text(
+        &code_line,
+        SCREEN_WIDTH / 2 - code_width / 2,
+        SCREEN_HEIGHT / 2 - 10,
+        24,
+        Color::SKYBLUE,
+    );
+
+    let restart = "SPACE: retry   TAB: new layout   ENTER: options";
     let restart_width = measure_text(restart, 20);
     d.draw_text(
         restart,
         SCREEN_WIDTH / 2 - restart_width / 2,
-        SCREEN_HEIGHT / 2 + 20,
+        SCREEN_HEIGHT / 2 + 30,
         20,
         Color::WHITE,
     );
@@ -622,31 +1239,81 @@ fn main() {
     rl.set_target_fps(60);
 
     let mut game = GameState::new();
+    let mut pad = Gamepad::new();
+    let mut options_open = false;
 
     while !rl.window_should_close() {
         // Input
-        if game.game_over {
-            if rl.is_key_pressed(KeyboardKey::KEY_SPACE) {
+        let pad_ev = pad.poll(&rl);
+        if game.game_over && options_open {
+            // Options screen: tweak and persist settings.
+            if rl.is_key_pressed(KeyboardKey::KEY_H) {
+                game.config.hud_style = game.config.hud_style.next();
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_MINUS) {
+                game.config.difficulty = (game.config.difficulty - 0.1).max(DIFFICULTY_MIN);
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_EQUAL) {
+                game.config.difficulty = (game.config.difficulty + 0.1).min(DIFFICULTY_MAX);
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_G) {
+                game.config.ghost_enabled = !game.config.ghost_enabled;
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_ENTER) {
+                game.config.save();
+                options_open = false;
+            }
+        } else if game.game_over {
+            // Text entry for the challenge code. Every alphanumeric goes into the
+            // code, uppercased, up to 16 characters, so shareable codes (including
+            // the default "FROGGER") can be typed in full; the menu lives on
+            // non-letter keys so no letter or digit is dropped. BACKSPACE trims, SPACE retries, TAB draws a new layout,
+            // ENTER opens options.
+            while let Some(c) = rl.get_char_pressed() {
+                if c.is_ascii_alphanumeric() && game.track_code.len() < 16 {
+                    game.track_code.push(c.to_ascii_uppercase());
+                }
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_BACKSPACE) {
+                game.track_code.pop();
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_ENTER) {
+                options_open = true;
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_TAB) {
+                game.race_try += 1;
+                game.reset();
+            }
+            if rl.is_key_pressed(KeyboardKey::KEY_SPACE) || pad_ev.confirm {
                 game.reset();
             }
         } else {
-            if rl.is_key_pressed(KeyboardKey::KEY_UP) || rl.is_key_pressed(KeyboardKey::KEY_W) {
+            let mut moved = false;
+            if rl.is_key_pressed(KeyboardKey::KEY_UP) || rl.is_key_pressed(KeyboardKey::KEY_W) || pad_ev.up {
                 game.frog.move_up();
+                moved = true;
             }
-            if rl.is_key_pressed(KeyboardKey::KEY_DOWN) || rl.is_key_pressed(KeyboardKey::KEY_S) {
+            if rl.is_key_pressed(KeyboardKey::KEY_DOWN) || rl.is_key_pressed(KeyboardKey::KEY_S) || pad_ev.down {
                 game.frog.move_down();
+                moved = true;
             }
-            if rl.is_key_pressed(KeyboardKey::KEY_LEFT) || rl.is_key_pressed(KeyboardKey::KEY_A) {
+            if rl.is_key_pressed(KeyboardKey::KEY_LEFT) || rl.is_key_pressed(KeyboardKey::KEY_A) || pad_ev.left {
                 game.frog.move_left();
+                moved = true;
             }
-            if rl.is_key_pressed(KeyboardKey::KEY_RIGHT) || rl.is_key_pressed(KeyboardKey::KEY_D) {
+            if rl.is_key_pressed(KeyboardKey::KEY_RIGHT) || rl.is_key_pressed(KeyboardKey::KEY_D) || pad_ev.right {
                 game.frog.move_right();
+                moved = true;
+            }
+            if moved {
+                game.start_race(rl.get_time());
             }
         }
 
         // Update
         let dt = rl.get_frame_time();
-        game.update(dt);
+        let now = rl.get_time();
+        game.update(dt, now);
 
         // Draw
         let mut d = rl.begin_drawing(&thread);
@@ -656,11 +1323,22 @@ fn main() {
         draw_goals(&mut d, &game.goals);
         draw_logs(&mut d, &game.logs);
         draw_cars(&mut d, &game.cars);
+        if game.config.ghost_enabled {
+            if let Some(ghost) = &game.ghost_best {
+                draw_ghost(&mut d, ghost, game.run_time);
+            }
+        }
         draw_frog(&mut d, &game.frog);
-        draw_hud(&mut d, game.lives, game.score);
+        let race_time = game.race_elapsed(now);
+        draw_hud(&mut d, game.config.hud_style, game.lives, game.score, &game.track_code, race_time);
+        draw_progress(&mut d, game.config.hud_style, &game.goals, &game.frog, now as f32);
 
         if game.game_over {
-            draw_game_over(&mut d, game.won);
+            if options_open {
+                draw_options(&mut d, &game.config);
+            } else {
+                draw_game_over(&mut d, game.won, &game.track_code, race_time, game.last_delta);
+            }
         }
     }
 }